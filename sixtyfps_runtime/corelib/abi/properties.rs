@@ -7,13 +7,42 @@
 
 use crate::abi::datastructures::ComponentRef;
 use std::cell::RefCell;
-use std::{
-    ops::DerefMut,
-    rc::{Rc, Weak},
-};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
 
 thread_local!(static CURRENT_BINDING : RefCell<Option<Rc<dyn PropertyNotify>>> = Default::default());
 
+/// Installs `new_current` as the thread-local binding currently being evaluated, restoring
+/// whatever was there before on drop and clearing `new_current`'s `updating` flag. Using a
+/// scope guard instead of a manual swap-back keeps `CURRENT_BINDING` correct even if the
+/// binding being evaluated panics: without it, a panicking binding would leave the
+/// thread-local pointing at a stale, already-unwound binding, corrupting dependency
+/// registration for everything evaluated afterwards, and would leave `updating` set so that
+/// every later evaluation of that property would be reported as a cycle.
+struct CurrentBindingGuard(Option<Rc<dyn PropertyNotify>>, PropertyHandle);
+
+impl CurrentBindingGuard {
+    fn new(new_current: PropertyHandle) -> Self {
+        let new_dyn: Rc<dyn PropertyNotify> = new_current.clone();
+        let old = CURRENT_BINDING.with(|cur_dep| cur_dep.borrow_mut().replace(new_dyn));
+        Self(old, new_current)
+    }
+}
+
+impl Drop for CurrentBindingGuard {
+    fn drop(&mut self) {
+        CURRENT_BINDING.with(|cur_dep| *cur_dep.borrow_mut() = self.0.take());
+        self.1.borrow_mut().updating = false;
+    }
+}
+
+// Properties marked dirty since the last call to `flush`, recorded by `mark_dirty` instead
+// of immediately recomputing them. `flush` drains this to compute a batched, glitch-free
+// evaluation order; `mark_dirty` itself also prunes entries that were already re-evaluated
+// through the plain pull path, so this stays bounded even if `flush` is never called.
+thread_local!(static PENDING_DIRTY: RefCell<Vec<Weak<dyn PropertyNotify>>> = Default::default());
+
 trait Binding {
     fn evaluate(self: Rc<Self>, value_ptr: *mut (), context: &EvaluationContext);
 }
@@ -23,8 +52,51 @@ struct PropertyImpl {
     /// Invariant: Must only be called with a pointer to the binding
     binding: Option<Rc<dyn Binding>>,
     dependencies: Vec<Weak<dyn PropertyNotify>>,
+    /// The properties this one has itself registered as a dependent of while last evaluating
+    /// its binding, i.e. the reverse of `dependencies`. Cleared and rebuilt on every
+    /// re-evaluation (see `Property::update`) so that a binding which stops reading some
+    /// property (e.g. a conditional taking the other branch) doesn't leave a stale entry in
+    /// that property's `dependencies` forever.
+    dependency_registrations: Vec<Weak<dyn PropertyNotify>>,
     dirty: bool,
-    //updating: bool,
+    /// Set for the duration of evaluating this property's binding. If we find it already set
+    /// when about to evaluate, the binding graph has a cycle; see `Property::update`.
+    updating: bool,
+    /// Type-erased trampoline that re-runs the owning `Property<T>::update`, used so that
+    /// `flush` can refresh a dependency without knowing its `T`. Installed by `set_binding`
+    /// and `on_changed`. Returns `Err` instead of swallowing a cycle detected while
+    /// re-evaluating, so `flush` can propagate it to its own caller.
+    update_trampoline: Option<Rc<dyn Fn(&EvaluationContext) -> Result<(), CircularDependencyError>>>,
+    /// Installed by `Property::on_changed` (`T: PartialEq` only); compares the old and new
+    /// value behind the same type erasure as `update_trampoline`, so `set`/`update` (which are
+    /// only `T: Clone`) can tell whether to fire `change_handlers` without themselves
+    /// requiring `PartialEq`. `None` for properties nobody has subscribed to, in which case
+    /// `change_handlers` is empty and there is nothing to compare for.
+    values_equal: Option<Box<dyn Fn(*const (), *const ()) -> bool>>,
+    /// Subscribers registered through `Property::on_changed`, type-erased for the same reason
+    /// as `update_trampoline`/`values_equal` rather than stored inline on `Property<T>`, so
+    /// that adding `on_changed` support doesn't change `Property<T>`'s `#[repr(C)]` layout.
+    /// Invoked with a pointer to the property's current value.
+    change_handlers: Vec<Box<dyn FnMut(*const ())>>,
+    /// Set through `sixtyfps_property_set_notify`; the FFI equivalent of
+    /// `Property::on_changed`, invoked whenever the C property's binding is re-evaluated.
+    notify: Option<CFunctionNotify>,
+}
+
+/// Type-erased change notification registered from C/C++, mirroring how
+/// `sixtyfps_property_set_binding` wraps a foreign binding closure.
+struct CFunctionNotify {
+    notify: extern "C" fn(*mut c_void, *const c_void),
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+}
+
+impl Drop for CFunctionNotify {
+    fn drop(&mut self) {
+        if let Some(x) = self.drop_user_data {
+            x(self.user_data)
+        }
+    }
 }
 
 /// PropertyNotify is the interface that allows keeping track of dependencies between
@@ -32,21 +104,65 @@ struct PropertyImpl {
 trait PropertyNotify {
     /// mark_dirty() is called to notify a property that its binding may need to be re-evaluated
     /// because one of its dependencies may have changed.
+    ///
+    /// This records the property into the thread-local pending set consumed by `flush`,
+    /// instead of eagerly recomputing it; the property will catch up the next time it's
+    /// pulled via `get()`/`update()`, or the next time someone calls `flush()`.
     fn mark_dirty(self: Rc<Self>);
     /// notify() is called to register the currently (thread-local) evaluating binding as a
     /// dependency for this property (self).
     fn register_current_binding_as_dependency(self: Rc<Self>);
+    /// Removes `dependent` (compared by pointer identity) from this property's `dependencies`
+    /// (its list of things that depend on it), if present. Called by a property on the other
+    /// end of the edge, right before it re-evaluates its own binding, to prune edges for
+    /// dependencies it's about to stop (or already stopped) reading.
+    fn remove_dependent(&self, dependent: *const ());
+    /// Records that the currently-evaluating binding (the caller of
+    /// `register_current_binding_as_dependency`) has registered itself as a dependent of
+    /// `dependency`, so that binding can find and prune that registration later via
+    /// `remove_dependent` if it stops reading `dependency`.
+    fn record_dependency_registration(&self, dependency: Weak<dyn PropertyNotify>);
+    /// The properties that depend on this one, i.e. that must be re-evaluated after this one
+    /// whenever it changes. Used by `flush` to walk the dependency graph.
+    fn dependents(&self) -> Vec<Weak<dyn PropertyNotify>>;
+    /// Whether this property is currently dirty. Used by `mark_dirty` to prune
+    /// `PENDING_DIRTY` entries that were already re-evaluated through the pull path.
+    fn is_dirty(&self) -> bool;
+    /// Re-evaluate this property's binding (if any) and clear its dirty flag. Does nothing if
+    /// the property isn't currently dirty. Called by `flush` once all of a node's own
+    /// dependencies have themselves been flushed. Returns `Err` if this property's binding is
+    /// already being evaluated further up the call stack (`flush`'s own ordering pass already
+    /// rules out any cycle reachable through `dependents`).
+    fn flush_evaluate(self: Rc<Self>, context: &EvaluationContext) -> Result<(), CircularDependencyError>;
 }
 
 impl PropertyNotify for RefCell<PropertyImpl> {
     fn mark_dirty(self: Rc<Self>) {
-        let mut v = vec![];
-        {
-            let mut dep = self.borrow_mut();
-            dep.dirty = true;
-            std::mem::swap(&mut dep.dependencies, &mut v);
+        if self.borrow().dirty {
+            // Already dirty: it was already recorded and its dependents already visited,
+            // so stop here. This also guards against unbounded recursion if the dependency
+            // graph (incorrectly) contains a cycle.
+            return;
         }
-        for d in &v {
+        self.borrow_mut().dirty = true;
+        let self_dyn: Rc<dyn PropertyNotify> = self.clone();
+        let new_weak = Rc::downgrade(&self_dyn);
+        let self_key = Rc::as_ptr(&self_dyn) as *const ();
+        PENDING_DIRTY.with(|pending| {
+            let mut pending = pending.borrow_mut();
+            // Prune entries that are either gone or were already re-evaluated (and so are no
+            // longer dirty) by the pull path without ever going through `flush`, instead of
+            // letting them pile up here forever: since this is the only place anything is
+            // ever added to `PENDING_DIRTY`, bounding it here is enough to bound it overall.
+            // An older entry for this same property is dropped too, since it is re-added below.
+            pending.retain(|w| {
+                Weak::as_ptr(w) as *const () != self_key
+                    && w.upgrade().is_some_and(|n| n.is_dirty())
+            });
+            pending.push(new_weak);
+        });
+        let dependents = self.borrow().dependencies.clone();
+        for d in dependents {
             if let Some(d) = d.upgrade() {
                 d.mark_dirty();
             }
@@ -56,10 +172,152 @@ impl PropertyNotify for RefCell<PropertyImpl> {
     fn register_current_binding_as_dependency(self: Rc<Self>) {
         CURRENT_BINDING.with(|cur_dep| {
             if let Some(m) = &(*cur_dep.borrow()) {
-                self.borrow_mut().dependencies.push(Rc::downgrade(m));
+                // Registering the same dependent twice (e.g. because the binding reads this
+                // property more than once, or simply reads it again on a later evaluation) must
+                // not grow `dependencies` without bound, so dedup on the dependent's identity.
+                let new_key = Rc::as_ptr(m) as *const ();
+                let mut lock = self.borrow_mut();
+                if !lock.dependencies.iter().any(|d| Weak::as_ptr(d) as *const () == new_key) {
+                    lock.dependencies.push(Rc::downgrade(m));
+                }
+                drop(lock);
+                // Also record the reverse edge on `m` so it can prune this registration later
+                // if it stops reading `self`.
+                let self_dyn: Rc<dyn PropertyNotify> = self.clone();
+                m.record_dependency_registration(Rc::downgrade(&self_dyn));
             }
         });
     }
+
+    fn remove_dependent(&self, dependent: *const ()) {
+        self.borrow_mut().dependencies.retain(|d| Weak::as_ptr(d) as *const () != dependent);
+    }
+
+    fn record_dependency_registration(&self, dependency: Weak<dyn PropertyNotify>) {
+        let new_key = Weak::as_ptr(&dependency) as *const ();
+        let mut lock = self.borrow_mut();
+        if !lock.dependency_registrations.iter().any(|d| Weak::as_ptr(d) as *const () == new_key)
+        {
+            lock.dependency_registrations.push(dependency);
+        }
+    }
+
+    fn dependents(&self) -> Vec<Weak<dyn PropertyNotify>> {
+        self.borrow().dependencies.clone()
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.borrow().dirty
+    }
+
+    fn flush_evaluate(self: Rc<Self>, context: &EvaluationContext) -> Result<(), CircularDependencyError> {
+        let trampoline = {
+            let lock = self.borrow();
+            if !lock.dirty {
+                return Ok(());
+            }
+            lock.update_trampoline.clone()
+        };
+        match trampoline {
+            // `Property::update` owns clearing `dirty` and firing `on_changed` subscribers.
+            Some(update) => update(context),
+            // No trampoline: either there is no binding to re-run (e.g. a plain value that
+            // was `set()` directly), so just clear the flag; or the binding was installed
+            // through the C API, which `flush` cannot evaluate without the value pointer, so
+            // it stays dirty and is evaluated by its next `sixtyfps_property_update`.
+            None => {
+                let mut lock = self.borrow_mut();
+                if lock.binding.is_none() {
+                    lock.dirty = false;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returned when a binding (directly or transitively) depends on itself: `flush` detects this
+/// ahead of time while ordering the dirtied properties, and `Property::update`/
+/// `sixtyfps_property_update`/`sync::SyncProperty::update` detect it at evaluation time for a
+/// self-cycle that's only discovered on its first run. Either way there is no valid value to
+/// compute for the cyclic read, so it gets the property's last known value instead.
+#[derive(Debug)]
+pub struct CircularDependencyError;
+
+impl std::fmt::Display for CircularDependencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "circular dependency between properties")
+    }
+}
+
+impl std::error::Error for CircularDependencyError {}
+
+/// Called from `update`/`sixtyfps_property_update` when a binding is re-entered while it is
+/// still being evaluated. There's no logger wired into this crate yet, so this also prints to
+/// stderr as a fallback diagnostic, but callers should not rely on that: `update` and its
+/// callers return/propagate `CircularDependencyError` instead, so a caller that wants to
+/// handle or suppress this can do so through the return value rather than through stderr.
+fn report_binding_cycle() {
+    eprintln!("{}", CircularDependencyError);
+}
+
+enum FlushVisitState {
+    Visiting,
+    Done,
+}
+
+fn flush_visit(
+    node: &Rc<dyn PropertyNotify>,
+    state: &mut HashMap<*const (), FlushVisitState>,
+    order: &mut Vec<Rc<dyn PropertyNotify>>,
+) -> Result<(), CircularDependencyError> {
+    let key = Rc::as_ptr(node) as *const ();
+    match state.get(&key) {
+        Some(FlushVisitState::Done) => return Ok(()),
+        Some(FlushVisitState::Visiting) => return Err(CircularDependencyError),
+        None => {}
+    }
+    state.insert(key, FlushVisitState::Visiting);
+    for dependent in node.dependents() {
+        if let Some(dependent) = dependent.upgrade() {
+            flush_visit(&dependent, state, order)?;
+        }
+    }
+    state.insert(key, FlushVisitState::Done);
+    order.push(node.clone());
+    Ok(())
+}
+
+/// Evaluate, in dependency order and exactly once each, every property that was marked dirty
+/// since the last call to `flush` (via `set`, `set_binding`, or the matching C entry points).
+/// A property whose binding was set through the C entry points is not evaluated here: it
+/// stays dirty and is evaluated by its next `sixtyfps_property_update`.
+///
+/// Left to `get`, a diamond dependency (A depending on both B and C, which both depend on D)
+/// can observe A in an inconsistent intermediate state, or recompute shared ancestors more
+/// than once, because each property is pulled independently. `flush` instead performs a DFS
+/// over the dependency graph reachable from the dirtied properties, building a reverse
+/// topological order, then evaluates each node once in that order: by the time a property's
+/// binding runs, everything it reads is already up to date.
+///
+/// Returns an error instead of recursing forever if the dirtied properties form a cycle found
+/// structurally while ordering them. A property whose binding reads itself for the first time
+/// (which can't be seen ahead of time from `dependents` alone) is not reported through this
+/// result: its reentrant read is logged via `report_binding_cycle` and gets the stale value,
+/// and `flush` returns `Ok`.
+pub fn flush(context: &EvaluationContext) -> Result<(), CircularDependencyError> {
+    let pending = PENDING_DIRTY.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    let mut order = vec![];
+    let mut state = HashMap::new();
+    for node in &pending {
+        if let Some(node) = node.upgrade() {
+            flush_visit(&node, &mut state, &mut order)?;
+        }
+    }
+    for node in order.into_iter().rev() {
+        node.flush_evaluate(context)?;
+    }
+    Ok(())
 }
 
 /// This structure contains what is required for the property engine to evaluate properties
@@ -106,6 +364,14 @@ pub struct Property<T: 'static> {
     inner: PropertyHandle,
     /// Only access when holding a lock of the inner refcell.
     value: core::cell::UnsafeCell<T>,
+    /// `Property<T>`'s fields (`Rc`, `UnsafeCell<T>`) are all themselves `Unpin`, so without
+    /// this marker `Pin::new(&property)` would type-check in safe code even though
+    /// `install_update_trampoline` captures `&property`'s address and dereferences it later
+    /// from `flush`; a caller could then safely move `property` out from under that pointer.
+    /// This field makes `Property<T>: !Unpin`, so only `Pin::new_unchecked` (an `unsafe`
+    /// assertion that the value won't move) can produce the `Pin<&Self>` `set_binding`/
+    /// `on_changed` require.
+    _pinned: core::marker::PhantomPinned,
 }
 
 impl<T: Clone + 'static> Property<T> {
@@ -118,8 +384,12 @@ impl<T: Clone + 'static> Property<T> {
     ///
     /// The context must be the constext matching the Component which contains this
     /// property
+    ///
+    /// `get` can't itself report a cycle in the binding graph (unlike `flush`) since it must
+    /// always return a `T`; on a cycle it logs via `report_binding_cycle` and returns the
+    /// stale value instead. Drive the engine through `flush` if you need to observe that error.
     pub fn get(&self, context: &EvaluationContext) -> T {
-        self.update(context);
+        let _ = self.update(context);
         self.inner.clone().register_current_binding_as_dependency();
         let _lock = self.inner.borrow();
         unsafe { (*(self.value.get() as *const T)).clone() }
@@ -130,14 +400,74 @@ impl<T: Clone + 'static> Property<T> {
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
     pub fn set(&self, t: T) {
-        {
+        let changed = {
             let mut lock = self.inner.borrow_mut();
             lock.binding = None;
             lock.dirty = false;
+            let changed = Self::values_differ(&lock, self.value.get() as *const (), &t);
             unsafe { *self.value.get() = t };
-        }
+            changed
+        };
+        // This property no longer has a binding (if it ever did), so whatever it previously
+        // registered itself as a dependent of no longer applies.
+        self.clear_dependency_registrations();
         self.inner.clone().mark_dirty();
         self.inner.borrow_mut().dirty = false;
+        if changed {
+            self.notify_change_handlers();
+        }
+    }
+
+    /// Removes this property from the `dependencies` list of everything it had registered
+    /// itself as a dependent of, and forgets those registrations. Called before a binding is
+    /// re-evaluated, or when it's replaced/cleared by `set`, so edges for dependencies that
+    /// are no longer read don't accumulate forever.
+    fn clear_dependency_registrations(&self) {
+        let stale = std::mem::take(&mut self.inner.borrow_mut().dependency_registrations);
+        let self_key = Rc::as_ptr(&self.inner) as *const ();
+        for dependency in stale {
+            if let Some(dependency) = dependency.upgrade() {
+                dependency.remove_dependent(self_key);
+            }
+        }
+    }
+
+    /// Compares `old` and `new` through `values_equal` if anyone has subscribed via
+    /// `on_changed` (which is what populates it; see that method for why this doesn't itself
+    /// require `T: PartialEq`). With no subscribers, `change_handlers` is empty, so there's
+    /// nothing to notify and the comparison is skipped.
+    fn values_differ(lock: &PropertyImpl, old: *const (), new: &T) -> bool {
+        match &lock.values_equal {
+            Some(eq) => !eq(old, new as *const T as *const ()),
+            None => false,
+        }
+    }
+
+    fn notify_change_handlers(&self) {
+        let value_ptr = self.value.get() as *const ();
+        for handler in self.inner.borrow_mut().change_handlers.iter_mut() {
+            handler(value_ptr);
+        }
+    }
+
+    /// Install the type-erased closure that lets `PropertyImpl` (which doesn't know `T`)
+    /// call back into this property's typed `update`. Used by `flush` to re-evaluate
+    /// bindings in dependency order.
+    ///
+    /// Only called from `set_binding`/`on_changed`, which take `self: Pin<&Self>`: the
+    /// closure installed here captures `self`'s address, so `Property` must not move again
+    /// once this has run, or that pointer dangles.
+    fn install_update_trampoline(&self) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.update_trampoline.is_some() {
+            return;
+        }
+        let self_ptr = self as *const Self;
+        inner.update_trampoline = Some(Rc::new(move |context: &EvaluationContext| {
+            // Safety: the caller of `set_binding`/`on_changed` pinned `self` before this
+            // trampoline was installed, so this address is guaranteed stable.
+            unsafe { (*self_ptr).update(context) }
+        }));
     }
 
     /// Set a binding to this property.
@@ -147,7 +477,11 @@ impl<T: Clone + 'static> Property<T> {
     ///
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
-    pub fn set_binding(&self, f: impl (Fn(&EvaluationContext) -> T) + 'static) {
+    ///
+    /// Takes `self: Pin<&Self>` because this installs a trampoline that captures `self`'s
+    /// address (see `install_update_trampoline`); the caller vouches the `Property` won't
+    /// move again afterwards (true for e.g. a field of a component kept behind an `Rc`).
+    pub fn set_binding(self: Pin<&Self>, f: impl (Fn(&EvaluationContext) -> T) + 'static) {
         struct BindingFunction {
             function: Box<dyn Fn(*mut (), &EvaluationContext)>,
         }
@@ -165,32 +499,87 @@ impl<T: Clone + 'static> Property<T> {
 
         let binding_object = Rc::new(BindingFunction { function: Box::new(real_binding) });
 
+        self.install_update_trampoline();
         self.inner.borrow_mut().binding = Some(binding_object);
         self.inner.clone().mark_dirty();
     }
 
-    /// Call the binding if the property is dirty to update the stored value
-    fn update(&self, context: &EvaluationContext) {
+    /// Call the binding if the property is dirty to update the stored value.
+    ///
+    /// Returns `Err(CircularDependencyError)` instead of recursing forever when called while
+    /// this property's own binding is already being evaluated further up the call stack (the
+    /// binding, directly or transitively, reads itself); that reentrant call leaves the stored
+    /// value as it is, and the outer evaluation then completes and stores its result.
+    fn update(&self, context: &EvaluationContext) -> Result<(), CircularDependencyError> {
         if !self.inner.borrow().dirty {
-            return;
+            return Ok(());
         }
-        let mut old: Option<Rc<dyn PropertyNotify>> = Some(self.inner.clone());
-        let mut lock =
-            self.inner.try_borrow_mut().expect("Circular dependency in binding evaluation");
-        if let Some(binding) = &lock.binding {
-            CURRENT_BINDING.with(|cur_dep| {
-                let mut m = cur_dep.borrow_mut();
-                std::mem::swap(m.deref_mut(), &mut old);
-            });
-            binding.clone().evaluate(self.value.get() as *mut _, context);
+        let binding = {
+            let mut lock = self.inner.borrow_mut();
+            if lock.updating {
+                // Re-entered while this exact binding is already being evaluated higher up
+                // the call stack: a cyclic binding graph. Report it and keep the stale value
+                // rather than recursing forever or corrupting `CURRENT_BINDING`.
+                report_binding_cycle();
+                return Err(CircularDependencyError);
+            }
+            lock.updating = true;
+            lock.binding.clone()
+        };
+        // Whatever this binding registered itself against last time it ran no longer
+        // necessarily holds: it may take a different branch this time (e.g. a conditional
+        // binding) and stop reading some of what it read before. Drop those edges now;
+        // evaluating below re-registers only what's actually read this pass.
+        self.clear_dependency_registrations();
+        let old = unsafe { (*(self.value.get() as *const T)).clone() };
+        if let Some(binding) = binding {
+            let _guard = CurrentBindingGuard::new(self.inner.clone());
+            binding.evaluate(self.value.get() as *mut _, context);
+        }
+        {
+            let mut lock = self.inner.borrow_mut();
             lock.dirty = false;
-            CURRENT_BINDING.with(|cur_dep| {
-                let mut m = cur_dep.borrow_mut();
-                std::mem::swap(m.deref_mut(), &mut old);
-                //somehow ptr_eq does not work as expected despite the pointer are equal
-                //debug_assert!(Rc::ptr_eq(&(self.inner.clone() as Rc<dyn PropertyNotify>), &old.unwrap()));
-            });
+            lock.updating = false;
+        }
+        let changed = Self::values_differ(&self.inner.borrow(), &old as *const T as *const (), unsafe {
+            &*(self.value.get() as *const T)
+        });
+        if changed {
+            self.notify_change_handlers();
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Property<T> {
+    /// Register a callback that is invoked every time this property's value genuinely
+    /// changes, whether because of a direct `set()` or because a binding was re-evaluated
+    /// and produced a different value.
+    ///
+    /// For a change coming from the binding, the callback only fires when this property's
+    /// binding is actually re-evaluated, which
+    /// happens lazily on `get()` or in a batch during `flush()`; nothing here re-evaluates a
+    /// binding as a side effect of `mark_dirty` alone, e.g. being marked dirty by a `set()` on
+    /// one of its dependencies. A driver that wants subscribers to fire without pulling each
+    /// property through `get()` (an animation driver or model adapter ticking once per frame,
+    /// say) should call `flush(context)` instead: it re-evaluates every dirty property reachable
+    /// from what was `set`/`set_binding` since the last `flush`, firing their `on_changed`
+    /// subscribers along the way, exactly as if each had been `get()`-pulled.
+    ///
+    /// Takes `self: Pin<&Self>` for the same reason as `set_binding`: this installs the same
+    /// update trampoline.
+    pub fn on_changed(self: Pin<&Self>, mut f: impl FnMut(&T) + 'static) {
+        let mut lock = self.inner.borrow_mut();
+        if lock.values_equal.is_none() {
+            lock.values_equal = Some(Box::new(|a: *const (), b: *const ()| unsafe {
+                *(a as *const T) == *(b as *const T)
+            }));
         }
+        lock.change_handlers.push(Box::new(move |ptr: *const ()| {
+            f(unsafe { &*(ptr as *const T) })
+        }));
+        drop(lock);
+        self.install_update_trampoline();
     }
 }
 
@@ -207,7 +596,9 @@ fn properties_simple_test() {
     });
     let compo = Rc::new(Component::default());
     let w = Rc::downgrade(&compo);
-    compo.area.set_binding(move |ctx| {
+    // Safety: `compo` is kept alive behind an `Rc` for the rest of the test, so its fields
+    // never move again.
+    unsafe { Pin::new_unchecked(&compo.area) }.set_binding(move |ctx| {
         let compo = w.upgrade().unwrap();
         compo.width.get(ctx) * compo.height.get(ctx)
     });
@@ -218,7 +609,7 @@ fn properties_simple_test() {
     assert_eq!(compo.area.get(&dummy_eval_context), 4 * 8);
 
     let w = Rc::downgrade(&compo);
-    compo.width.set_binding(move |ctx| {
+    unsafe { Pin::new_unchecked(&compo.width) }.set_binding(move |ctx| {
         let compo = w.upgrade().unwrap();
         compo.height.get(ctx) * 2
     });
@@ -227,6 +618,233 @@ fn properties_simple_test() {
     assert_eq!(compo.area.get(&dummy_eval_context), 8 * 8 * 2);
 }
 
+#[test]
+fn properties_on_changed_test() {
+    #[derive(Default)]
+    struct Component {
+        width: Property<i32>,
+        height: Property<i32>,
+        area: Property<i32>,
+    }
+    let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+        vtable::VRef::from_raw(core::ptr::NonNull::dangling(), core::ptr::NonNull::dangling())
+    });
+    let compo = Rc::new(Component::default());
+
+    let w = Rc::downgrade(&compo);
+    // Safety: `compo` is kept alive behind an `Rc` for the rest of the test, so its fields
+    // never move again.
+    unsafe { Pin::new_unchecked(&compo.area) }.set_binding(move |ctx| {
+        let compo = w.upgrade().unwrap();
+        compo.width.get(ctx) * compo.height.get(ctx)
+    });
+
+    let seen = Rc::new(RefCell::new(vec![]));
+    let seen2 = seen.clone();
+    unsafe { Pin::new_unchecked(&compo.area) }.on_changed(move |area| seen2.borrow_mut().push(*area));
+
+    compo.width.set(4);
+    compo.height.set(8);
+    assert!(seen.borrow().is_empty());
+
+    // Pulling recomputes area and notifies since the value actually changed.
+    assert_eq!(compo.area.get(&dummy_eval_context), 32);
+    assert_eq!(*seen.borrow(), vec![32]);
+
+    // Setting the same value again still marks area dirty, but recomputing it yields the
+    // same value, so subscribers must not be notified again.
+    compo.height.set(8);
+    assert_eq!(compo.area.get(&dummy_eval_context), 32);
+    assert_eq!(*seen.borrow(), vec![32]);
+
+    compo.height.set(2);
+    assert_eq!(compo.area.get(&dummy_eval_context), 8);
+    assert_eq!(*seen.borrow(), vec![32, 8]);
+}
+
+#[test]
+fn properties_on_changed_fires_via_flush_without_get_test() {
+    // A driver that never calls `get()` on the subscribed property itself (an animation driver
+    // or model adapter ticking once per frame, say) still observes changes, as long as it
+    // drives the engine with `flush()`.
+    #[derive(Default)]
+    struct Component {
+        width: Property<i32>,
+        height: Property<i32>,
+        area: Property<i32>,
+    }
+    let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+        vtable::VRef::from_raw(core::ptr::NonNull::dangling(), core::ptr::NonNull::dangling())
+    });
+    let compo = Rc::new(Component::default());
+
+    let w = Rc::downgrade(&compo);
+    // Safety: `compo` is kept alive behind an `Rc` for the rest of the test, so its fields
+    // never move again.
+    unsafe { Pin::new_unchecked(&compo.area) }.set_binding(move |ctx| {
+        let compo = w.upgrade().unwrap();
+        compo.width.get(ctx) * compo.height.get(ctx)
+    });
+    // Prime the binding once so `area` is registered as a dependent of `width`/`height`.
+    assert_eq!(compo.area.get(&dummy_eval_context), 0);
+
+    let seen = Rc::new(RefCell::new(vec![]));
+    let seen2 = seen.clone();
+    unsafe { Pin::new_unchecked(&compo.area) }.on_changed(move |area| seen2.borrow_mut().push(*area));
+
+    compo.width.set(4);
+    compo.height.set(8);
+    flush(&dummy_eval_context).unwrap();
+
+    // Notified purely by `flush`; `area.get()` is never called in this test.
+    assert_eq!(*seen.borrow(), vec![32]);
+}
+
+#[test]
+fn properties_flush_test() {
+    // A diamond: sum depends on both doubled and tripled, which both depend on base.
+    #[derive(Default)]
+    struct Component {
+        base: Property<i32>,
+        doubled: Property<i32>,
+        tripled: Property<i32>,
+        sum: Property<i32>,
+    }
+    let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+        vtable::VRef::from_raw(core::ptr::NonNull::dangling(), core::ptr::NonNull::dangling())
+    });
+    let compo = Rc::new(Component::default());
+
+    let w = Rc::downgrade(&compo);
+    // Safety: `compo` is kept alive behind an `Rc` for the rest of the test, so its fields
+    // never move again.
+    unsafe { Pin::new_unchecked(&compo.doubled) }.set_binding(move |ctx| compo_base(&w, ctx) * 2);
+    let w = Rc::downgrade(&compo);
+    unsafe { Pin::new_unchecked(&compo.tripled) }.set_binding(move |ctx| compo_base(&w, ctx) * 3);
+    let w = Rc::downgrade(&compo);
+    unsafe { Pin::new_unchecked(&compo.sum) }.set_binding(move |ctx| {
+        let compo = w.upgrade().unwrap();
+        compo.doubled.get(ctx) + compo.tripled.get(ctx)
+    });
+
+    // Prime all the bindings once so they're registered as dependents of `base`.
+    assert_eq!(compo.sum.get(&dummy_eval_context), 0);
+
+    let seen = Rc::new(RefCell::new(vec![]));
+    let seen2 = seen.clone();
+    unsafe { Pin::new_unchecked(&compo.sum) }.on_changed(move |sum| seen2.borrow_mut().push(*sum));
+
+    compo.base.set(5);
+    flush(&dummy_eval_context).unwrap();
+
+    // `flush` evaluated `doubled` and `tripled` before `sum`, so `sum` only ever sees the
+    // fully-consistent result, never a partially-updated intermediate state.
+    assert_eq!(*seen.borrow(), vec![25]);
+    // And reading the properties afterwards is a pure cache hit, not a recompute.
+    assert_eq!(compo.sum.get(&dummy_eval_context), 25);
+
+    fn compo_base(w: &Weak<Component>, ctx: &EvaluationContext) -> i32 {
+        w.upgrade().unwrap().base.get(ctx)
+    }
+}
+
+#[test]
+fn properties_conditional_binding_prunes_unused_dependency_test() {
+    #[derive(Default)]
+    struct Component {
+        flag: Property<bool>,
+        a: Property<i32>,
+        b: Property<i32>,
+        chosen: Property<i32>,
+    }
+    let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+        vtable::VRef::from_raw(core::ptr::NonNull::dangling(), core::ptr::NonNull::dangling())
+    });
+    let compo = Rc::new(Component::default());
+    compo.flag.set(true);
+    compo.a.set(1);
+    compo.b.set(2);
+
+    let w = Rc::downgrade(&compo);
+    // Safety: `compo` is kept alive behind an `Rc` for the rest of the test, so its fields
+    // never move again.
+    unsafe { Pin::new_unchecked(&compo.chosen) }.set_binding(move |ctx| {
+        let compo = w.upgrade().unwrap();
+        if compo.flag.get(ctx) {
+            compo.a.get(ctx)
+        } else {
+            compo.b.get(ctx)
+        }
+    });
+    assert_eq!(compo.chosen.get(&dummy_eval_context), 1);
+    // Only `flag` and `a` were read on this pass, so only they have `chosen` registered as a
+    // dependent; `b` doesn't, even though it's also a field of the same component.
+    assert_eq!(compo.a.inner.borrow().dependencies.len(), 1);
+    assert_eq!(compo.b.inner.borrow().dependencies.len(), 0);
+
+    compo.flag.set(false);
+    assert_eq!(compo.chosen.get(&dummy_eval_context), 2);
+    // Having switched branches, `a`'s now-stale edge was pruned instead of sticking around
+    // forever, and `b` is registered in its place.
+    assert_eq!(compo.a.inner.borrow().dependencies.len(), 0);
+    assert_eq!(compo.b.inner.borrow().dependencies.len(), 1);
+}
+
+#[test]
+fn properties_flush_cycle_test() {
+    #[derive(Default)]
+    struct Component {
+        a: Property<i32>,
+        b: Property<i32>,
+    }
+    let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+        vtable::VRef::from_raw(core::ptr::NonNull::dangling(), core::ptr::NonNull::dangling())
+    });
+    let compo = Component::default();
+
+    // Wire up a cycle directly in the dependents graph ("a" depends on "b", "b" depends on
+    // "a"), the way evaluating `a.get()` from within `b`'s binding (and vice versa) would.
+    let a_dyn: Rc<dyn PropertyNotify> = compo.a.inner.clone();
+    let b_dyn: Rc<dyn PropertyNotify> = compo.b.inner.clone();
+    compo.b.inner.borrow_mut().dependencies.push(Rc::downgrade(&a_dyn));
+    compo.a.inner.borrow_mut().dependencies.push(Rc::downgrade(&b_dyn));
+
+    compo.a.inner.clone().mark_dirty();
+
+    assert!(flush(&dummy_eval_context).is_err());
+}
+
+#[test]
+fn properties_update_reentrancy_test() {
+    // A binding that (directly or transitively) reads its own property while it is being
+    // evaluated used to deadlock the `RefCell` and panic with "already borrowed". It must
+    // instead be detected as a cycle, fall back to the stale value for the reentrant read,
+    // and let the outer evaluation complete normally.
+    #[derive(Default)]
+    struct Component {
+        a: Property<i32>,
+    }
+    let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+        vtable::VRef::from_raw(core::ptr::NonNull::dangling(), core::ptr::NonNull::dangling())
+    });
+    let compo = Rc::new(Component::default());
+
+    let w = Rc::downgrade(&compo);
+    // Safety: `compo` is kept alive behind an `Rc` for the rest of the test, so its fields
+    // never move again.
+    unsafe { Pin::new_unchecked(&compo.a) }.set_binding(move |ctx| {
+        let compo = w.upgrade().unwrap();
+        // Reentrant: `a` is still `updating` at this point, so this must report the cycle
+        // and hand back the stale value (0) instead of panicking or recursing forever.
+        compo.a.get(ctx) + 1
+    });
+
+    assert_eq!(compo.a.get(&dummy_eval_context), 1);
+    // The binding is still dirty-free and usable afterwards; the guard correctly restored
+    // `updating` to false despite the reentrant call.
+    assert_eq!(compo.a.get(&dummy_eval_context), 1);
+}
+
 #[allow(non_camel_case_types)]
 type c_void = ();
 #[repr(C)]
@@ -246,36 +864,65 @@ pub unsafe extern "C" fn sixtyfps_property_init(out: *mut PropertyHandleOpaque)
 /// To be called before accessing the value
 ///
 /// (same as Property::update and PopertyImpl::notify)
+///
+/// Returns `false` when called while this property's own binding is already being evaluated
+/// further up the call stack (the binding, directly or transitively, reads itself), in which
+/// case the value is left unchanged (its last known one); returns `true` otherwise. Callers that don't
+/// need to distinguish the two can ignore the return value: a cycle is also reported through
+/// `report_binding_cycle`.
 #[no_mangle]
 pub unsafe extern "C" fn sixtyfps_property_update(
     out: *const PropertyHandleOpaque,
     context: *const EvaluationContext,
     val: *mut c_void,
-) {
+) -> bool {
     let inner = &*(out as *const PropertyHandle);
 
     if !inner.borrow().dirty {
         inner.clone().register_current_binding_as_dependency();
-        return;
+        return true;
     }
-    let mut old: Option<Rc<dyn PropertyNotify>> = Some(inner.clone());
-    let mut lock = inner.try_borrow_mut().expect("Circular dependency in binding evaluation");
-    if let Some(binding) = &lock.binding {
-        CURRENT_BINDING.with(|cur_dep| {
-            let mut m = cur_dep.borrow_mut();
-            std::mem::swap(m.deref_mut(), &mut old);
-        });
-        binding.clone().evaluate(val, &*context);
+    let binding = {
+        let mut lock = inner.borrow_mut();
+        if lock.updating {
+            // Re-entered while this exact binding is already being evaluated higher up the
+            // call stack: a cyclic binding graph. Report it and keep the stale value rather
+            // than recursing forever or corrupting `CURRENT_BINDING`.
+            report_binding_cycle();
+            return false;
+        }
+        lock.updating = true;
+        lock.binding.clone()
+    };
+    if let Some(binding) = binding {
+        let _guard = CurrentBindingGuard::new(inner.clone());
+        binding.evaluate(val, &*context);
+        if let Some(notify) = &inner.borrow().notify {
+            (notify.notify)(notify.user_data, val);
+        }
+    }
+    {
+        let mut lock = inner.borrow_mut();
         lock.dirty = false;
-        CURRENT_BINDING.with(|cur_dep| {
-            let mut m = cur_dep.borrow_mut();
-            std::mem::swap(m.deref_mut(), &mut old);
-            //somehow ptr_eq does not work as expected despite the pointer are equal
-            //debug_assert!(Rc::ptr_eq(&(inner.clone() as Rc<dyn PropertyNotify>), &old.unwrap()));
-        });
+        lock.updating = false;
     }
-    core::mem::drop(lock);
     inner.clone().register_current_binding_as_dependency();
+    true
+}
+
+/// Register a callback invoked whenever this property's binding is re-evaluated, passing
+/// back the freshly written value so the caller can compare it against what it cached
+/// previously. This is the FFI counterpart of `Property::on_changed`; unlike the Rust API,
+/// the change-vs-no-change comparison is left to the caller since `T` isn't known here.
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_property_set_notify(
+    out: *const PropertyHandleOpaque,
+    notify: extern "C" fn(*mut c_void, *const c_void),
+    user_data: *mut c_void,
+    drop_user_data: Option<extern "C" fn(*mut c_void)>,
+) {
+    let inner = &*(out as *const PropertyHandle);
+    inner.borrow_mut().notify = Some(CFunctionNotify { notify, user_data, drop_user_data });
 }
 
 /// Mark the fact that the property was changed and that its binding need to be removed, and
@@ -294,8 +941,9 @@ pub unsafe extern "C" fn sixtyfps_property_set_changed(out: *const PropertyHandl
 /// The current implementation will do usually two memory alocation:
 ///  1. the allocation from the calling code to allocate user_data
 ///  2. the box allocation within this binding
-/// It might be possible to reduce that by passing something with a
-/// vtable, so there is the need for less memory allocation.
+///
+/// `sixtyfps_property_set_binding_foreign` avoids the second allocation for callers that can
+/// hand over ownership of their captured state directly; prefer it in generated code.
 #[no_mangle]
 pub unsafe extern "C" fn sixtyfps_property_set_binding(
     out: *const PropertyHandleOpaque,
@@ -332,8 +980,306 @@ pub unsafe extern "C" fn sixtyfps_property_set_binding(
     inner.clone().mark_dirty();
 }
 
+/// Describes how to evaluate, clone and release an `instance` pointer owned by the binding
+/// engine, the way a `Box<dyn ForeignOwnable>` would, but without requiring a second,
+/// separately-allocated `user_data`: the vtable is supplied once (typically a `'static`
+/// from generated code), and `instance` is whatever that vtable knows how to operate on,
+/// inline or heap-allocated by the caller however it likes.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BindingVTable {
+    /// Evaluate the binding, writing the result through `value_ptr` (same convention as
+    /// `sixtyfps_property_set_binding`'s `binding` callback).
+    pub evaluate: extern "C" fn(*mut c_void, &EvaluationContext, *mut c_void),
+    /// Release `instance`. `None` if it owns nothing that needs releasing.
+    pub drop: Option<extern "C" fn(*mut c_void)>,
+}
+
+/// A `Binding` whose evaluation and lifetime are entirely described by a `BindingVTable`
+/// instead of a Rust closure, so that `sixtyfps_property_set_binding_foreign` only needs to
+/// allocate the `Rc<ForeignBinding>` itself, not an extra box to wrap the caller's state.
+///
+/// No `Clone` impl: bindings are always stored and shared as `Rc<dyn Binding>`, and the
+/// engine only ever clones that `Rc`, never the `ForeignBinding` itself, so there is no
+/// `instance`-duplication path to support (or vtable slot to describe one).
+struct ForeignBinding {
+    vtable: BindingVTable,
+    instance: *mut c_void,
+}
+
+impl Drop for ForeignBinding {
+    fn drop(&mut self) {
+        if let Some(drop_instance) = self.vtable.drop {
+            drop_instance(self.instance)
+        }
+    }
+}
+
+impl Binding for ForeignBinding {
+    fn evaluate(self: Rc<Self>, value_ptr: *mut (), context: &EvaluationContext) {
+        (self.vtable.evaluate)(self.instance, context, value_ptr as *mut c_void);
+    }
+}
+
+/// Set a binding described by a `BindingVTable` plus an `instance` pointer the binding engine
+/// takes ownership of, instead of a bare C function pointer plus separately-allocated
+/// `user_data` (as `sixtyfps_property_set_binding` requires). This is the FFI counterpart of
+/// a `ForeignOwnable`: generated code that already owns a heap (or thin-DST/inline) block
+/// describing its captured state can hand that block over directly, avoiding the second,
+/// engine-side allocation that wraps it.
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_property_set_binding_foreign(
+    out: *const PropertyHandleOpaque,
+    vtable: BindingVTable,
+    instance: *mut c_void,
+) {
+    let inner = &*(out as *const PropertyHandle);
+    inner.borrow_mut().binding = Some(Rc::new(ForeignBinding { vtable, instance }));
+    inner.clone().mark_dirty();
+}
+
 /// Destroy handle
 #[no_mangle]
 pub unsafe extern "C" fn sixtyfps_property_drop(handle: *mut PropertyHandleOpaque) {
     core::ptr::read(handle as *mut PropertyHandle);
 }
+
+/// A thread-safe counterpart of the default property engine above.
+///
+/// `Property<T>` is deliberately single-threaded: `PropertyHandle` is an `Rc<RefCell<..>>`,
+/// and the binding currently being evaluated is tracked in the `CURRENT_BINDING`
+/// `thread_local!`. That's the right zero-overhead default (and it's what the `#[repr(C)]`
+/// layout and C ABI above target), but it means a `Property` can never be stored somewhere
+/// `Send + Sync`, so a layout pass or a data model can't evaluate its bindings on a worker
+/// thread.
+///
+/// `SyncProperty<T>` is the same lazy, dependency-tracked binding model, generalized to
+/// `Arc<Mutex<..>>` storage. The one behavioral difference (beyond locking instead of
+/// borrowing) is that there is no `thread_local!`: the binding currently being evaluated is
+/// threaded explicitly through `SyncEvaluationContext`, since relying on ambient per-thread
+/// state would make it unclear which thread a given evaluation is "pinned" to. Glitch-free
+/// batched `flush` and `on_changed` subscriptions are not reimplemented here yet; this module
+/// only generalizes the interior-mutability choice (`Mutex` instead of `RefCell`) underneath
+/// `get`/`set`/`set_binding`.
+///
+/// Disabled by default; enable with the `sync` feature to opt into `Send + Sync` properties
+/// where they're needed, without paying for locking on the common single-threaded path.
+#[cfg(feature = "sync")]
+pub mod sync {
+    use super::{report_binding_cycle, CircularDependencyError, EvaluationContext};
+    use std::sync::{Arc, Mutex, Weak};
+
+    trait SyncBinding: Send + Sync {
+        fn evaluate(self: Arc<Self>, value_ptr: *mut (), context: &SyncEvaluationContext);
+    }
+
+    #[derive(Default)]
+    struct SyncPropertyImpl {
+        binding: Option<Arc<dyn SyncBinding>>,
+        dependencies: Vec<Weak<Mutex<SyncPropertyImpl>>>,
+        dirty: bool,
+    }
+
+    type SyncPropertyHandle = Arc<Mutex<SyncPropertyImpl>>;
+
+    fn mark_dirty(inner: &SyncPropertyHandle) {
+        let dependents = {
+            let mut lock = inner.lock().unwrap();
+            if lock.dirty {
+                return;
+            }
+            lock.dirty = true;
+            lock.dependencies.clone()
+        };
+        for d in dependents {
+            if let Some(d) = d.upgrade() {
+                mark_dirty(&d);
+            }
+        }
+    }
+
+    /// Thread-safe counterpart of `EvaluationContext`. In addition to the component context,
+    /// it explicitly carries the stack of bindings currently being evaluated, innermost last,
+    /// taking the place of the single-threaded engine's `CURRENT_BINDING` thread-local so the
+    /// same logical evaluation doesn't depend on which thread it happens to run on. Keeping
+    /// the whole stack (not just the innermost binding) is what lets `update` below detect a
+    /// binding that transitively depends on itself without ever re-locking its own `Mutex`.
+    pub struct SyncEvaluationContext<'a> {
+        pub context: &'a EvaluationContext<'a>,
+        current_bindings: Mutex<Vec<SyncPropertyHandle>>,
+    }
+
+    impl<'a> SyncEvaluationContext<'a> {
+        pub fn new(context: &'a EvaluationContext<'a>) -> Self {
+            Self { context, current_bindings: Mutex::new(Vec::new()) }
+        }
+
+        fn register_current_binding_as_dependency(&self, inner: &SyncPropertyHandle) {
+            if let Some(current) = self.current_bindings.lock().unwrap().last() {
+                // Registering the same dependent twice (e.g. because the binding reads this
+                // property more than once, or simply reads it again on a later evaluation) must
+                // not grow `dependencies` without bound, so dedup on the dependent's identity.
+                let new_key = Arc::as_ptr(current) as *const ();
+                let mut lock = inner.lock().unwrap();
+                if !lock.dependencies.iter().any(|d| Weak::as_ptr(d) as *const () == new_key) {
+                    lock.dependencies.push(Arc::downgrade(current));
+                }
+            }
+        }
+    }
+
+    /// A `Send + Sync` property, storing its state behind `Arc<Mutex<..>>` instead of
+    /// `Rc<RefCell<..>>`. See the module documentation for how this differs from `Property<T>`.
+    #[derive(Default)]
+    pub struct SyncProperty<T> {
+        inner: SyncPropertyHandle,
+        /// Only access while holding `inner`'s lock, exactly like `Property::value`.
+        value: core::cell::UnsafeCell<T>,
+    }
+
+    // Safety: `value` is only ever read or written while `inner`'s `Mutex` is locked (see
+    // `get`/`set`/`update` below), so `SyncProperty<T>` is as `Sync` as `Mutex<T>` itself.
+    unsafe impl<T: Send> Sync for SyncProperty<T> {}
+    unsafe impl<T: Send> Send for SyncProperty<T> {}
+
+    impl<T: Clone + PartialEq + Send + 'static> SyncProperty<T> {
+        /// Get the value of the property, evaluating its binding first if it is dirty.
+        ///
+        /// If called directly or indirectly from another property's binding evaluation
+        /// (i.e. `context.current_bindings` is non-empty), a dependency on that property is
+        /// registered.
+        ///
+        /// Like `Property::get`, this can't report a cycle in the binding graph through its
+        /// return value; on one it logs via `report_binding_cycle` and returns the stale value.
+        pub fn get(&self, context: &SyncEvaluationContext) -> T {
+            let _ = self.update(context);
+            context.register_current_binding_as_dependency(&self.inner);
+            let _lock = self.inner.lock().unwrap();
+            unsafe { (*(self.value.get() as *const T)).clone() }
+        }
+
+        /// Change the value of this property, marking dependent properties dirty.
+        pub fn set(&self, t: T) {
+            {
+                let mut lock = self.inner.lock().unwrap();
+                lock.binding = None;
+                lock.dirty = false;
+                unsafe { *self.value.get() = t };
+            }
+            mark_dirty(&self.inner);
+            self.inner.lock().unwrap().dirty = false;
+        }
+
+        /// Set a binding to this property, evaluated lazily on `get`.
+        pub fn set_binding(
+            &self,
+            f: impl (Fn(&SyncEvaluationContext) -> T) + Send + Sync + 'static,
+        ) {
+            struct BindingFunction<T> {
+                function: Box<dyn Fn(&SyncEvaluationContext) -> T + Send + Sync>,
+            }
+
+            impl<T> SyncBinding for BindingFunction<T>
+            where
+                T: Send + 'static,
+            {
+                fn evaluate(self: Arc<Self>, value_ptr: *mut (), context: &SyncEvaluationContext) {
+                    unsafe { *(value_ptr as *mut T) = (self.function)(context) };
+                }
+            }
+
+            let binding_object: Arc<dyn SyncBinding> =
+                Arc::new(BindingFunction { function: Box::new(f) });
+
+            self.inner.lock().unwrap().binding = Some(binding_object);
+            mark_dirty(&self.inner);
+        }
+
+        /// Re-evaluate the binding if the property is dirty.
+        ///
+        /// Returns `Err(CircularDependencyError)` instead of deadlocking on `inner`'s own
+        /// `Mutex` if this binding (directly or transitively) reads itself; the stale value is
+        /// kept either way.
+        fn update(&self, context: &SyncEvaluationContext) -> Result<(), CircularDependencyError> {
+            // Reject a binding that transitively depends on itself *before* touching
+            // `self.inner`'s lock: that lock is held for the rest of this function (including
+            // across `evaluate`, see the safety comment below), so a same-thread re-entrant
+            // call would deadlock on it rather than have anything to check.
+            if context.current_bindings.lock().unwrap().iter().any(|b| Arc::ptr_eq(b, &self.inner))
+            {
+                report_binding_cycle();
+                return Err(CircularDependencyError);
+            }
+
+            let mut lock = self.inner.lock().unwrap();
+            if !lock.dirty {
+                return Ok(());
+            }
+            let binding = lock.binding.clone();
+            if let Some(binding) = binding {
+                context.current_bindings.lock().unwrap().push(self.inner.clone());
+                // Pop even if `evaluate` panics, exactly like `CurrentBindingGuard` does for
+                // the single-threaded engine.
+                struct PopGuard<'a, 'b>(&'a SyncEvaluationContext<'b>);
+                impl<'a, 'b> Drop for PopGuard<'a, 'b> {
+                    fn drop(&mut self) {
+                        self.0.current_bindings.lock().unwrap().pop();
+                    }
+                }
+                let _guard = PopGuard(context);
+                // Safety: `lock` stays held from here through the end of this function, so no
+                // concurrent `get`/`set` on another thread can observe `value` while it's being
+                // written by `evaluate` -- this is what the `unsafe impl Sync` above relies on.
+                binding.evaluate(self.value.get() as *mut (), context);
+            }
+            lock.dirty = false;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn sync_properties_simple_test() {
+        use std::sync::Arc as StdArc;
+
+        #[derive(Default)]
+        struct Component {
+            width: SyncProperty<i32>,
+            height: SyncProperty<i32>,
+            area: SyncProperty<i32>,
+        }
+        let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+            vtable::VRef::from_raw(
+                core::ptr::NonNull::dangling(),
+                core::ptr::NonNull::dangling(),
+            )
+        });
+        let sync_context = SyncEvaluationContext::new(&dummy_eval_context);
+
+        let compo = StdArc::new(Component::default());
+        let w = StdArc::downgrade(&compo);
+        compo.area.set_binding(move |ctx| {
+            let compo = w.upgrade().unwrap();
+            compo.width.get(ctx) * compo.height.get(ctx)
+        });
+        compo.width.set(4);
+        compo.height.set(8);
+        assert_eq!(compo.area.get(&sync_context), 4 * 8);
+
+        // Evaluating from a different thread works exactly the same, since nothing here
+        // relies on thread-local state.
+        let compo2 = compo.clone();
+        std::thread::spawn(move || {
+            let dummy_eval_context = EvaluationContext::for_root_component(unsafe {
+                vtable::VRef::from_raw(
+                    core::ptr::NonNull::dangling(),
+                    core::ptr::NonNull::dangling(),
+                )
+            });
+            let sync_context = SyncEvaluationContext::new(&dummy_eval_context);
+            compo2.height.set(2);
+            assert_eq!(compo2.area.get(&sync_context), 4 * 2);
+        })
+        .join()
+        .unwrap();
+    }
+}